@@ -1,6 +1,9 @@
+mod rpc;
+
 use std::str::FromStr;
 
 use axum::{
+    extract::Path,
     http::StatusCode,
     routing::{get, post},
     Json, Router,
@@ -9,13 +12,22 @@ use axum::{
 use serde::{Deserialize, Serialize};
 use solana_program::example_mocks::solana_sdk::system_instruction;
 use solana_sdk::{
+    hash::Hash,
+    instruction::{AccountMeta, Instruction},
+    message::Message,
     pubkey::Pubkey,
     signature::{Keypair, Signature},
     signer::Signer,
+    transaction::Transaction,
+};
+use mpl_token_metadata::{instructions::CreateMetadataAccountV3Builder, types::DataV2};
+use spl_associated_token_account::{
+    get_associated_token_address, instruction::create_associated_token_account_idempotent,
 };
-use spl_associated_token_account::get_associated_token_address;
 use spl_token::{id as token_program_id, instruction::initialize_mint};
 
+use rpc::RpcClient;
+
 #[tokio::main]
 async fn main() {
     let port = std::env::var("PORT").unwrap_or_else(|_| "3000".to_string());
@@ -35,7 +47,23 @@ async fn main() {
         .route("/send/sol", post(send_sol))
         .route("/send/sol", get(incorrect_method))
         .route("/send/token", post(send_token))
-        .route("/send/token", get(incorrect_method));
+        .route("/send/token", get(incorrect_method))
+        .route("/send/conditional", post(send_conditional))
+        .route("/send/conditional", get(incorrect_method))
+        .route("/tx/build", post(build_transaction))
+        .route("/tx/build", get(incorrect_method))
+        .route("/tx/send", post(send_transaction))
+        .route("/tx/send", get(incorrect_method))
+        .route("/balance/:pubkey", get(get_balance))
+        .route("/balance/:pubkey", post(incorrect_method))
+        .route("/blockhash", get(get_blockhash))
+        .route("/blockhash", post(incorrect_method))
+        .route("/airdrop", post(airdrop))
+        .route("/airdrop", get(incorrect_method))
+        .route("/token/account/create", post(create_token_account))
+        .route("/token/account/create", get(incorrect_method))
+        .route("/nft/create", post(create_nft))
+        .route("/nft/create", get(incorrect_method));
 
     let listener = tokio::net::TcpListener::bind(format!("0.0.0.0:{}", port))
         .await
@@ -70,6 +98,278 @@ async fn create_keypair() -> (StatusCode, Json<Response>) {
     (StatusCode::OK, Json(response))
 }
 
+/// Builds the `InitializeMint` instruction shared by `create_token` and `/tx/build`.
+fn build_initialize_mint_instruction(
+    mint: &str,
+    mint_authority: &str,
+    decimals: u8,
+) -> Result<Instruction, String> {
+    let mint_pubkey = Pubkey::from_str(mint).map_err(|_| "Invalid mint address".to_string())?;
+    let mint_authority = Pubkey::from_str(mint_authority)
+        .map_err(|_| "Invalid mint authority address".to_string())?;
+    initialize_mint(&token_program_id(), &mint_pubkey, &mint_authority, None, decimals)
+        .map_err(|_| "Failed to build InitializeMint instruction".to_string())
+}
+
+/// Builds the SOL transfer instruction shared by `send_sol` and `/tx/build`.
+fn build_transfer_sol_instruction(from: &str, to: &str, lamports: u64) -> Result<Instruction, String> {
+    let from = Pubkey::from_str(from).map_err(|_| "Invalid from address".to_string())?;
+    let to = Pubkey::from_str(to).map_err(|_| "Invalid to address".to_string())?;
+    Ok(system_instruction::transfer(&from, &to, lamports))
+}
+
+/// Parses multisig signer pubkeys (bs58) threaded into `transfer`/`mint_to` as the signer slice.
+fn parse_signer_pubkeys(signers: &[String]) -> Result<Vec<Pubkey>, String> {
+    signers
+        .iter()
+        .map(|s| Pubkey::from_str(s).map_err(|_| "Invalid signer address".to_string()))
+        .collect()
+}
+
+/// Builds the SPL token transfer instruction shared by `send_token` and `/tx/build`.
+fn build_spl_transfer_instruction(
+    destination: &str,
+    mint: &str,
+    owner: &str,
+    amount: u64,
+    signers: &[String],
+) -> Result<Instruction, String> {
+    let destination =
+        Pubkey::from_str(destination).map_err(|_| "Invalid destination address".to_string())?;
+    let mint = Pubkey::from_str(mint).map_err(|_| "Invalid mint address".to_string())?;
+    let owner = Pubkey::from_str(owner).map_err(|_| "Invalid owner address".to_string())?;
+    let signer_pubkeys = parse_signer_pubkeys(signers)?;
+    let signer_refs: Vec<&Pubkey> = signer_pubkeys.iter().collect();
+    let source_ata = get_associated_token_address(&owner, &mint);
+    let dest_ata = get_associated_token_address(&destination, &mint);
+    spl_token::instruction::transfer(
+        &spl_token::id(),
+        &source_ata,
+        &dest_ata,
+        &owner,
+        &signer_refs,
+        amount,
+    )
+    .map_err(|_| "Failed to build transfer instruction".to_string())
+}
+
+/// Builds the SPL `MintTo` instruction shared by `mint_token` and `/tx/build`.
+fn build_mint_to_instruction(
+    mint: &str,
+    authority: &str,
+    destination: &str,
+    amount: u64,
+    signers: &[String],
+) -> Result<Instruction, String> {
+    let mint = Pubkey::from_str(mint).map_err(|_| "Invalid mint address".to_string())?;
+    let mint_authority =
+        Pubkey::from_str(authority).map_err(|_| "Invalid authority address".to_string())?;
+    let destination =
+        Pubkey::from_str(destination).map_err(|_| "Invalid destination address".to_string())?;
+    let signer_pubkeys = parse_signer_pubkeys(signers)?;
+    let signer_refs: Vec<&Pubkey> = signer_pubkeys.iter().collect();
+    let dest_ata = get_associated_token_address(&destination, &mint);
+    spl_token::instruction::mint_to(
+        &spl_token::id(),
+        &mint,
+        &dest_ata,
+        &mint_authority,
+        &signer_refs,
+        amount,
+    )
+    .map_err(|_| "Failed to build mint_to instruction".to_string())
+}
+
+/// Builds an idempotent associated-token-account creation instruction shared by the token
+/// handlers' `createDestination` flag and `/token/account/create`.
+fn build_create_ata_instruction(payer: &str, owner: &str, mint: &str) -> Result<Instruction, String> {
+    let payer = Pubkey::from_str(payer).map_err(|_| "Invalid payer address".to_string())?;
+    let owner = Pubkey::from_str(owner).map_err(|_| "Invalid owner address".to_string())?;
+    let mint = Pubkey::from_str(mint).map_err(|_| "Invalid mint address".to_string())?;
+    Ok(create_associated_token_account_idempotent(
+        &payer,
+        &owner,
+        &mint,
+        &token_program_id(),
+    ))
+}
+
+/// Program id of the Metaplex token metadata program.
+fn metadata_program_id() -> Pubkey {
+    Pubkey::from_str("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s")
+        .expect("valid metaplex token metadata program id")
+}
+
+/// Builds the `CreateMetadataAccountV3` instruction backing `/nft/create`, with the metadata
+/// PDA derived from `["metadata", metadata_program_id, mint]`.
+fn build_create_metadata_instruction(
+    mint: &str,
+    mint_authority: &str,
+    payer: &str,
+    name: String,
+    symbol: String,
+    uri: String,
+    seller_fee_basis_points: u16,
+) -> Result<Instruction, String> {
+    let mint = Pubkey::from_str(mint).map_err(|_| "Invalid mint address".to_string())?;
+    let mint_authority =
+        Pubkey::from_str(mint_authority).map_err(|_| "Invalid mint authority address".to_string())?;
+    let payer = Pubkey::from_str(payer).map_err(|_| "Invalid payer address".to_string())?;
+    let metadata_program = metadata_program_id();
+    let (metadata, _bump) = Pubkey::find_program_address(
+        &[b"metadata", metadata_program.as_ref(), mint.as_ref()],
+        &metadata_program,
+    );
+    Ok(CreateMetadataAccountV3Builder::new()
+        .metadata(metadata)
+        .mint(mint)
+        .mint_authority(mint_authority)
+        .payer(payer)
+        .update_authority(mint_authority, true)
+        .data(DataV2 {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points,
+            creators: None,
+            collection: None,
+            uses: None,
+        })
+        .is_mutable(true)
+        .instruction())
+}
+
+/// Program id for the conditional-payment escrow program `/send/conditional` targets.
+///
+/// No such program ships with this crate, so the id is read from
+/// `CONDITIONAL_PAYMENT_PROGRAM_ID` with no default; callers must point it at their own
+/// deployment of a program that understands the `Release`/`Cancel` instruction layout below.
+fn conditional_payment_program_id() -> Result<Pubkey, String> {
+    let id = std::env::var("CONDITIONAL_PAYMENT_PROGRAM_ID")
+        .map_err(|_| "CONDITIONAL_PAYMENT_PROGRAM_ID is not configured".to_string())?;
+    Pubkey::from_str(&id).map_err(|_| "Invalid CONDITIONAL_PAYMENT_PROGRAM_ID".to_string())
+}
+
+/// Space reserved for the escrow account's terms (from, to, releaseAt, witness, cancelable).
+const CONDITIONAL_PAYMENT_ACCOUNT_SPACE: u64 = 128;
+
+/// Builds the instruction that creates and funds the escrow account, owned by the
+/// conditional-payment program, for `/send/conditional`.
+fn build_conditional_escrow_create_instruction(
+    from: &Pubkey,
+    escrow: &Pubkey,
+    lamports: u64,
+) -> Result<Instruction, String> {
+    let program_id = conditional_payment_program_id()?;
+    Ok(system_instruction::create_account(
+        from,
+        escrow,
+        lamports,
+        CONDITIONAL_PAYMENT_ACCOUNT_SPACE,
+        &program_id,
+    ))
+}
+
+/// Builds the release instruction: funds move from `escrow` to `to` once `release_at` has
+/// passed or `witness` (if set) has signed. Data layout: `[0u8, has_release_at, release_at: i64
+/// LE, has_witness]`, where the 8 `release_at` bytes are present only when `has_release_at` is 1,
+/// matching the conditional-payment program's expected instruction format.
+fn build_conditional_release_instruction(
+    escrow: &Pubkey,
+    to: &Pubkey,
+    release_at: Option<i64>,
+    witness: Option<&Pubkey>,
+) -> Result<Instruction, String> {
+    let program_id = conditional_payment_program_id()?;
+    let mut data = vec![0u8];
+    match release_at {
+        Some(timestamp) => {
+            data.push(1);
+            data.extend_from_slice(&timestamp.to_le_bytes());
+        }
+        None => data.push(0),
+    }
+    data.push(witness.is_some() as u8);
+    let mut accounts = vec![
+        AccountMeta::new(*escrow, false),
+        AccountMeta::new(*to, false),
+    ];
+    if let Some(witness) = witness {
+        accounts.push(AccountMeta::new_readonly(*witness, true));
+    }
+    Ok(Instruction { program_id, accounts, data })
+}
+
+/// Builds the cancel instruction: returns the escrowed funds to `from` before release.
+/// Data layout: `[1u8]`, matching the conditional-payment program's expected instruction format.
+fn build_conditional_cancel_instruction(escrow: &Pubkey, from: &Pubkey) -> Result<Instruction, String> {
+    let program_id = conditional_payment_program_id()?;
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(*escrow, false),
+            AccountMeta::new(*from, true),
+        ],
+        data: vec![1u8],
+    })
+}
+
+/// Converts a built `Instruction` into the wire `InstructionData` shape used across handlers.
+fn instruction_to_data(ix: &Instruction) -> InstructionData {
+    InstructionData {
+        program_id: bs58::encode(ix.program_id.to_bytes()).into_string(),
+        accounts: ix
+            .accounts
+            .iter()
+            .map(|meta| AccountMetaInfo {
+                pubkey: bs58::encode(meta.pubkey.to_bytes()).into_string(),
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect(),
+        instruction_data: base64::encode(ix.data.clone()),
+    }
+}
+
+/// Serializes a handler's built instructions, preserving the original single-`InstructionData`
+/// response shape when there's exactly one (e.g. no `createDestination` ATA prepended) so
+/// existing callers aren't broken, and falling back to `InstructionListData` otherwise.
+fn instructions_response_value(instructions: &[Instruction]) -> serde_json::Value {
+    match instructions {
+        [ix] => serde_json::to_value(instruction_to_data(ix)).unwrap(),
+        ixs => serde_json::to_value(InstructionListData {
+            instructions: ixs.iter().map(instruction_to_data).collect(),
+        })
+        .unwrap(),
+    }
+}
+
+/// Same as `instructions_response_value`, but for `/send/token` specifically: its
+/// pre-existing single-instruction shape is `TokenTransferData`/`SendTokenResponse`
+/// (`isSigner`, no `is_writable`), not the generic `InstructionData`/`AccountMetaInfo`
+/// used elsewhere, so it needs its own serialization to avoid breaking existing callers.
+fn send_token_response_value(instructions: &[Instruction]) -> serde_json::Value {
+    match instructions {
+        [ix] => serde_json::to_value(TokenTransferData {
+            program_id: bs58::encode(ix.program_id.to_bytes()).into_string(),
+            accounts: ix
+                .accounts
+                .iter()
+                .map(|meta| SendTokenResponse {
+                    pubkey: bs58::encode(meta.pubkey.to_bytes()).into_string(),
+                    isSigner: meta.is_signer,
+                })
+                .collect(),
+            instruction_data: base64::encode(ix.data.clone()),
+        })
+        .unwrap(),
+        ixs => serde_json::to_value(InstructionListData {
+            instructions: ixs.iter().map(instruction_to_data).collect(),
+        })
+        .unwrap(),
+    }
+}
+
 async fn create_token(Json(payload): Json<MintToken>) -> (StatusCode, Json<Response>) {
     let (mint, mint_authority, decimals) =
         match (&payload.mint, &payload.mintAuthority, payload.decimals) {
@@ -84,46 +384,10 @@ async fn create_token(Json(payload): Json<MintToken>) -> (StatusCode, Json<Respo
                 )
             }
         };
-    let mint_pubkey = match Pubkey::from_str(mint) {
-        Ok(pk) => pk,
-        Err(_) => {
-            return (
-                StatusCode::BAD_REQUEST,
-                Json(Response::Error {
-                    success: false,
-                    error: "Invalid mint address".to_string(),
-                }),
-            )
-        }
-    };
-    let mint_authority = match Pubkey::from_str(mint_authority) {
-        Ok(pk) => pk,
-        Err(_) => {
-            return (
-                StatusCode::BAD_REQUEST,
-                Json(Response::Error {
-                    success: false,
-                    error: "Invalid mint authority address".to_string(),
-                }),
-            )
-        }
-    };
-    let ix = match initialize_mint(
-        &token_program_id(),
-        &mint_pubkey,
-        &mint_authority,
-        None,
-        decimals,
-    ) {
+    let ix = match build_initialize_mint_instruction(mint, mint_authority, decimals) {
         Ok(ix) => ix,
-        Err(_) => {
-            return (
-                StatusCode::BAD_REQUEST,
-                Json(Response::Error {
-                    success: false,
-                    error: "Failed to build InitializeMint instruction".to_string(),
-                }),
-            )
+        Err(error) => {
+            return (StatusCode::BAD_REQUEST, Json(Response::Error { success: false, error }))
         }
     };
     let accounts: Vec<AccountMetaInfo> = ix
@@ -266,31 +530,12 @@ async fn send_sol(Json(payload): Json<SendSol>) -> (StatusCode, Json<Response>)
             )
         }
     };
-    let from = match Pubkey::from_str(from) {
-        Ok(pk) => pk,
-        Err(_) => {
-            return (
-                StatusCode::BAD_REQUEST,
-                Json(Response::Error {
-                    success: false,
-                    error: "Invalid from address".to_string(),
-                }),
-            )
-        }
-    };
-    let to = match Pubkey::from_str(to) {
-        Ok(pk) => pk,
-        Err(_) => {
-            return (
-                StatusCode::BAD_REQUEST,
-                Json(Response::Error {
-                    success: false,
-                    error: "Invalid to address".to_string(),
-                }),
-            )
+    let ix = match build_transfer_sol_instruction(from, to, lamports) {
+        Ok(ix) => ix,
+        Err(error) => {
+            return (StatusCode::BAD_REQUEST, Json(Response::Error { success: false, error }))
         }
     };
-    let ix = system_instruction::transfer(&from, &to, lamports);
     let response = Response::Success {
         success: true,
         data: serde_json::to_value(SolTransferData {
@@ -327,76 +572,122 @@ async fn send_token(Json(payload): Json<SendToken>) -> (StatusCode, Json<Respons
             )
         }
     };
-    let destination = match Pubkey::from_str(destination) {
-        Ok(pk) => pk,
-        Err(_) => {
+    let signers = payload.signers.clone().unwrap_or_default();
+    let mut instructions = Vec::new();
+    if payload.createDestination.unwrap_or(false) {
+        match build_create_ata_instruction(owner, destination, mint) {
+            Ok(ix) => instructions.push(ix),
+            Err(error) => {
+                return (StatusCode::BAD_REQUEST, Json(Response::Error { success: false, error }))
+            }
+        }
+    }
+    match build_spl_transfer_instruction(destination, mint, owner, amount, &signers) {
+        Ok(ix) => instructions.push(ix),
+        Err(error) => {
+            return (StatusCode::BAD_REQUEST, Json(Response::Error { success: false, error }))
+        }
+    }
+    let response = Response::Success {
+        success: true,
+        data: send_token_response_value(&instructions),
+    };
+    (StatusCode::OK, Json(response))
+}
+
+async fn send_conditional(Json(payload): Json<ConditionalSend>) -> (StatusCode, Json<Response>) {
+    let (from, to, lamports) = match (&payload.from, &payload.to, payload.lamports) {
+        (Some(from), Some(to), Some(lamports)) => (from, to, lamports),
+        _ => {
             return (
                 StatusCode::BAD_REQUEST,
                 Json(Response::Error {
                     success: false,
-                    error: "Invalid destination address".to_string(),
+                    error: "Missing required fields".to_string(),
                 }),
             )
         }
     };
-    let mint = match Pubkey::from_str(mint) {
+    let from_pubkey = match Pubkey::from_str(from) {
         Ok(pk) => pk,
         Err(_) => {
             return (
                 StatusCode::BAD_REQUEST,
                 Json(Response::Error {
                     success: false,
-                    error: "Invalid mint address".to_string(),
+                    error: "Invalid from address".to_string(),
                 }),
             )
         }
     };
-    let owner = match Pubkey::from_str(owner) {
+    let to_pubkey = match Pubkey::from_str(to) {
         Ok(pk) => pk,
         Err(_) => {
             return (
                 StatusCode::BAD_REQUEST,
                 Json(Response::Error {
                     success: false,
-                    error: "Invalid owner address".to_string(),
+                    error: "Invalid to address".to_string(),
                 }),
             )
         }
     };
-    let source_ata = get_associated_token_address(&owner, &mint);
-    let dest_ata = get_associated_token_address(&destination, &mint);
-    let ix = match spl_token::instruction::transfer(
-        &spl_token::id(),
-        &source_ata,
-        &dest_ata,
-        &owner,
-        &[],
-        amount,
+    let witness_pubkey = match &payload.witness {
+        Some(witness) => match Pubkey::from_str(witness) {
+            Ok(pk) => Some(pk),
+            Err(_) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(Response::Error {
+                        success: false,
+                        error: "Invalid witness address".to_string(),
+                    }),
+                )
+            }
+        },
+        None => None,
+    };
+    let cancelable = payload.cancelable.unwrap_or(false);
+    let escrow = Keypair::new();
+    let create_ix = match build_conditional_escrow_create_instruction(
+        &from_pubkey,
+        &escrow.pubkey(),
+        lamports,
     ) {
         Ok(ix) => ix,
-        Err(_) => {
-            return (
-                StatusCode::BAD_REQUEST,
-                Json(Response::Error {
-                    success: false,
-                    error: "Failed to build transfer instruction".to_string(),
-                }),
-            )
+        Err(error) => {
+            return (StatusCode::BAD_REQUEST, Json(Response::Error { success: false, error }))
         }
     };
+    let release_ix = match build_conditional_release_instruction(
+        &escrow.pubkey(),
+        &to_pubkey,
+        payload.releaseAt,
+        witness_pubkey.as_ref(),
+    ) {
+        Ok(ix) => ix,
+        Err(error) => {
+            return (StatusCode::BAD_REQUEST, Json(Response::Error { success: false, error }))
+        }
+    };
+    let cancel_instructions = if cancelable {
+        match build_conditional_cancel_instruction(&escrow.pubkey(), &from_pubkey) {
+            Ok(ix) => vec![instruction_to_data(&ix)],
+            Err(error) => {
+                return (StatusCode::BAD_REQUEST, Json(Response::Error { success: false, error }))
+            }
+        }
+    } else {
+        Vec::new()
+    };
     let response = Response::Success {
         success: true,
-        data: serde_json::to_value(TokenTransferData {
-            program_id: bs58::encode(ix.program_id.to_bytes()).into_string(),
-            accounts: ix
-                .accounts
-                .iter()
-                .map(|meta| SendTokenResponse {
-                    pubkey: bs58::encode(meta.pubkey.to_bytes()).into_string(),
-                    isSigner: meta.is_signer,
-                })
-                .collect::<Vec<_>>(),
-            instruction_data: base64::encode(ix.data),
+        data: serde_json::to_value(ConditionalSendData {
+            createInstructions: vec![instruction_to_data(&create_ix)],
+            releaseInstructions: vec![instruction_to_data(&release_ix)],
+            cancelInstructions: cancel_instructions,
+            escrowPubkey: bs58::encode(escrow.pubkey().to_bytes()).into_string(),
+            escrowSecret: bs58::encode(escrow.to_bytes()).into_string(),
         })
         .unwrap(),
     };
@@ -423,83 +714,406 @@ async fn mint_token(Json(payload): Json<MintTokenRequest>) -> (StatusCode, Json<
             )
         }
     };
-    let mint = match Pubkey::from_str(mint) {
-        Ok(pk) => pk,
-        Err(_) => {
+    let signers = payload.signers.clone().unwrap_or_default();
+    let mut instructions = Vec::new();
+    if payload.createDestination.unwrap_or(false) {
+        match build_create_ata_instruction(authority, destination, mint) {
+            Ok(ix) => instructions.push(ix),
+            Err(error) => {
+                return (StatusCode::BAD_REQUEST, Json(Response::Error { success: false, error }))
+            }
+        }
+    }
+    match build_mint_to_instruction(mint, authority, destination, amount, &signers) {
+        Ok(ix) => instructions.push(ix),
+        Err(error) => {
+            return (StatusCode::BAD_REQUEST, Json(Response::Error { success: false, error }))
+        }
+    }
+    let response = Response::Success {
+        success: true,
+        data: instructions_response_value(&instructions),
+    };
+    (StatusCode::OK, Json(response))
+}
+
+async fn create_token_account(
+    Json(payload): Json<CreateTokenAccount>,
+) -> (StatusCode, Json<Response>) {
+    let (owner, mint, payer) = match (&payload.owner, &payload.mint, &payload.payer) {
+        (Some(owner), Some(mint), Some(payer)) => (owner, mint, payer),
+        _ => {
             return (
                 StatusCode::BAD_REQUEST,
                 Json(Response::Error {
                     success: false,
-                    error: "Invalid mint address".to_string(),
+                    error: "Missing required fields".to_string(),
                 }),
             )
         }
     };
-    let mint_authority = match Pubkey::from_str(authority) {
+    let ix = match build_create_ata_instruction(payer, owner, mint) {
+        Ok(ix) => ix,
+        Err(error) => {
+            return (StatusCode::BAD_REQUEST, Json(Response::Error { success: false, error }))
+        }
+    };
+    let response = Response::Success {
+        success: true,
+        data: serde_json::to_value(instruction_to_data(&ix)).unwrap(),
+    };
+    (StatusCode::OK, Json(response))
+}
+
+async fn create_nft(Json(payload): Json<CreateNft>) -> (StatusCode, Json<Response>) {
+    let (mint, mint_authority, payer, destination, name, symbol, uri) = match (
+        &payload.mint,
+        &payload.mintAuthority,
+        &payload.payer,
+        &payload.destination,
+        &payload.name,
+        &payload.symbol,
+        &payload.uri,
+    ) {
+        (
+            Some(mint),
+            Some(mint_authority),
+            Some(payer),
+            Some(destination),
+            Some(name),
+            Some(symbol),
+            Some(uri),
+        ) => (mint, mint_authority, payer, destination, name, symbol, uri),
+        _ => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(Response::Error {
+                    success: false,
+                    error: "Missing required fields".to_string(),
+                }),
+            )
+        }
+    };
+    let seller_fee_basis_points = payload.sellerFeeBasisPoints.unwrap_or(0);
+    let mut instructions = Vec::new();
+    for ix in [
+        build_initialize_mint_instruction(mint, mint_authority, 0),
+        build_create_ata_instruction(payer, destination, mint),
+        build_mint_to_instruction(mint, mint_authority, destination, 1, &[]),
+        build_create_metadata_instruction(
+            mint,
+            mint_authority,
+            payer,
+            name.clone(),
+            symbol.clone(),
+            uri.clone(),
+            seller_fee_basis_points,
+        ),
+    ] {
+        match ix {
+            Ok(ix) => instructions.push(ix),
+            Err(error) => {
+                return (StatusCode::BAD_REQUEST, Json(Response::Error { success: false, error }))
+            }
+        }
+    }
+    let response = Response::Success {
+        success: true,
+        data: serde_json::to_value(InstructionListData {
+            instructions: instructions.iter().map(instruction_to_data).collect(),
+        })
+        .unwrap(),
+    };
+    (StatusCode::OK, Json(response))
+}
+
+/// Dispatches a single `/tx/build` instruction spec to its builder function.
+fn instruction_for_spec(spec: &InstructionSpec) -> Result<Instruction, String> {
+    match spec {
+        InstructionSpec::TransferSol { from, to, lamports } => {
+            build_transfer_sol_instruction(from, to, *lamports)
+        }
+        InstructionSpec::SplTransfer { destination, mint, owner, amount } => {
+            build_spl_transfer_instruction(destination, mint, owner, *amount, &[])
+        }
+        InstructionSpec::MintTo { mint, authority, destination, amount } => {
+            build_mint_to_instruction(mint, authority, destination, *amount, &[])
+        }
+        InstructionSpec::InitializeMint { mint, mintAuthority, decimals } => {
+            build_initialize_mint_instruction(mint, mintAuthority, *decimals)
+        }
+    }
+}
+
+/// Assembles and signs a transaction from already-built instructions.
+fn build_signed_transaction(
+    instructions: &[Instruction],
+    fee_payer: &Pubkey,
+    signers: &[Keypair],
+    blockhash: Hash,
+) -> Result<Transaction, String> {
+    let message = Message::new(instructions, Some(fee_payer));
+    let mut transaction = Transaction::new_unsigned(message);
+    let signer_refs: Vec<&Keypair> = signers.iter().collect();
+    transaction
+        .try_sign(&signer_refs, blockhash)
+        .map_err(|_| "Failed to sign transaction".to_string())?;
+    Ok(transaction)
+}
+
+async fn build_transaction(Json(payload): Json<BuildTransaction>) -> (StatusCode, Json<Response>) {
+    let (specs, fee_payer, signer_secrets) =
+        match (&payload.instructions, &payload.feePayer, &payload.signers) {
+            (Some(specs), Some(fee_payer), Some(signer_secrets))
+                if !specs.is_empty() && !signer_secrets.is_empty() =>
+            {
+                (specs, fee_payer, signer_secrets)
+            }
+            _ => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(Response::Error {
+                        success: false,
+                        error: "Missing required fields".to_string(),
+                    }),
+                )
+            }
+        };
+    let fee_payer = match Pubkey::from_str(fee_payer) {
         Ok(pk) => pk,
         Err(_) => {
             return (
                 StatusCode::BAD_REQUEST,
                 Json(Response::Error {
                     success: false,
-                    error: "Invalid authority address".to_string(),
+                    error: "Invalid fee payer address".to_string(),
                 }),
             )
         }
     };
-    let destination = match Pubkey::from_str(destination) {
-        Ok(pk) => pk,
+    let mut instructions: Vec<Instruction> = Vec::with_capacity(specs.len());
+    for spec in specs {
+        match instruction_for_spec(spec) {
+            Ok(ix) => instructions.push(ix),
+            Err(error) => {
+                return (StatusCode::BAD_REQUEST, Json(Response::Error { success: false, error }))
+            }
+        }
+    }
+    let signers: Vec<Keypair> = match signer_secrets
+        .iter()
+        .map(|secret| {
+            bs58::decode(secret)
+                .into_vec()
+                .ok()
+                .and_then(|bytes| Keypair::from_bytes(&bytes).ok())
+        })
+        .collect::<Option<Vec<Keypair>>>()
+    {
+        Some(signers) => signers,
+        None => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(Response::Error {
+                    success: false,
+                    error: "Invalid secret key".to_string(),
+                }),
+            )
+        }
+    };
+    let rpc_client = RpcClient::from_env();
+    let blockhash = match rpc_client.get_latest_blockhash().await {
+        Ok(blockhash) => blockhash,
+        Err(error) => {
+            return (StatusCode::BAD_REQUEST, Json(Response::Error { success: false, error }))
+        }
+    };
+    let blockhash = match Hash::from_str(&blockhash) {
+        Ok(hash) => hash,
         Err(_) => {
             return (
                 StatusCode::BAD_REQUEST,
                 Json(Response::Error {
                     success: false,
-                    error: "Invalid destination address".to_string(),
+                    error: "Invalid blockhash returned by cluster".to_string(),
                 }),
             )
         }
     };
-    let dest_ata = get_associated_token_address(&destination, &mint);
-    let ix = match spl_token::instruction::mint_to(
-        &spl_token::id(),
-        &mint,
-        &dest_ata,
-        &mint_authority,
-        &[],
-        amount,
-    ) {
-        Ok(ix) => ix,
+    let transaction = match build_signed_transaction(&instructions, &fee_payer, &signers, blockhash)
+    {
+        Ok(transaction) => transaction,
+        Err(error) => {
+            return (StatusCode::BAD_REQUEST, Json(Response::Error { success: false, error }))
+        }
+    };
+    let serialized = match bincode::serialize(&transaction) {
+        Ok(bytes) => bytes,
         Err(_) => {
             return (
                 StatusCode::BAD_REQUEST,
                 Json(Response::Error {
                     success: false,
-                    error: "Failed to build mint_to instruction".to_string(),
+                    error: "Failed to serialize transaction".to_string(),
                 }),
             )
         }
     };
-    let accounts: Vec<AccountMetaInfo> = ix
-        .accounts
-        .iter()
-        .map(|meta| AccountMetaInfo {
-            pubkey: bs58::encode(meta.pubkey.to_bytes()).into_string(),
-            is_signer: meta.is_signer,
-            is_writable: meta.is_writable,
-        })
-        .collect();
     let response = Response::Success {
         success: true,
-        data: serde_json::to_value(InstructionData {
-            program_id: bs58::encode(ix.program_id.to_bytes()).into_string(),
-            accounts: accounts,
-            instruction_data: base64::encode(ix.data),
+        data: serde_json::to_value(BuiltTransactionData {
+            transaction: base64::encode(serialized),
         })
         .unwrap(),
     };
     (StatusCode::OK, Json(response))
 }
 
+async fn send_transaction(Json(payload): Json<SendTransaction>) -> (StatusCode, Json<Response>) {
+    let transaction = match &payload.transaction {
+        Some(transaction) => transaction,
+        None => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(Response::Error {
+                    success: false,
+                    error: "Missing required fields".to_string(),
+                }),
+            )
+        }
+    };
+    if base64::decode(transaction).is_err() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(Response::Error {
+                success: false,
+                error: "Invalid transaction encoding".to_string(),
+            }),
+        );
+    }
+    let rpc_client = RpcClient::from_env();
+    match rpc_client.send_transaction(transaction).await {
+        Ok(signature) => (
+            StatusCode::OK,
+            Json(Response::Success {
+                success: true,
+                data: serde_json::to_value(TransactionSignatureData { signature }).unwrap(),
+            }),
+        ),
+        Err(error) => (
+            StatusCode::BAD_REQUEST,
+            Json(Response::Error {
+                success: false,
+                error,
+            }),
+        ),
+    }
+}
+
+async fn get_balance(Path(pubkey): Path<String>) -> (StatusCode, Json<Response>) {
+    if Pubkey::from_str(&pubkey).is_err() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(Response::Error {
+                success: false,
+                error: "Invalid pubkey".to_string(),
+            }),
+        );
+    }
+    let rpc_client = RpcClient::from_env();
+    let lamports = match rpc_client.get_balance(&pubkey).await {
+        Ok(lamports) => lamports,
+        Err(error) => {
+            return (StatusCode::BAD_REQUEST, Json(Response::Error { success: false, error }))
+        }
+    };
+    let owner = match rpc_client.get_account_info(&pubkey).await {
+        Ok(account_info) => account_info
+            .get("value")
+            .and_then(|v| v.get("owner"))
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        Err(error) => {
+            return (StatusCode::BAD_REQUEST, Json(Response::Error { success: false, error }))
+        }
+    };
+    let response = Response::Success {
+        success: true,
+        data: serde_json::to_value(BalanceData { lamports, owner }).unwrap(),
+    };
+    (StatusCode::OK, Json(response))
+}
+
+async fn airdrop(Json(payload): Json<AirdropRequest>) -> (StatusCode, Json<Response>) {
+    let (pubkey, lamports) = match (&payload.pubkey, payload.lamports) {
+        (Some(pubkey), Some(lamports)) => (pubkey, lamports),
+        _ => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(Response::Error {
+                    success: false,
+                    error: "Missing required fields".to_string(),
+                }),
+            )
+        }
+    };
+    if Pubkey::from_str(pubkey).is_err() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(Response::Error {
+                success: false,
+                error: "Invalid pubkey".to_string(),
+            }),
+        );
+    }
+    let rpc_client = RpcClient::from_env();
+    if rpc_client.is_mainnet() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(Response::Error {
+                success: false,
+                error: "Airdrops are not available on mainnet".to_string(),
+            }),
+        );
+    }
+    match rpc_client.request_airdrop(pubkey, lamports).await {
+        Ok(signature) => (
+            StatusCode::OK,
+            Json(Response::Success {
+                success: true,
+                data: serde_json::to_value(TransactionSignatureData { signature }).unwrap(),
+            }),
+        ),
+        Err(error) => (
+            StatusCode::BAD_REQUEST,
+            Json(Response::Error {
+                success: false,
+                error,
+            }),
+        ),
+    }
+}
+
+async fn get_blockhash() -> (StatusCode, Json<Response>) {
+    let rpc_client = RpcClient::from_env();
+    match rpc_client.get_latest_blockhash().await {
+        Ok(blockhash) => (
+            StatusCode::OK,
+            Json(Response::Success {
+                success: true,
+                data: serde_json::to_value(BlockhashData { blockhash }).unwrap(),
+            }),
+        ),
+        Err(error) => (
+            StatusCode::BAD_REQUEST,
+            Json(Response::Error {
+                success: false,
+                error,
+            }),
+        ),
+    }
+}
+
 #[derive(Serialize, Debug)]
 #[serde(untagged)]
 enum Response {
@@ -541,19 +1155,135 @@ struct SendSol {
 }
 
 #[derive(Deserialize)]
+#[allow(non_snake_case)]
 struct SendToken {
     destination: Option<String>,
     mint: Option<String>,
     owner: Option<String>,
     amount: Option<u64>,
+    signers: Option<Vec<String>>,
+    createDestination: Option<bool>,
 }
 
 #[derive(Deserialize)]
+#[allow(non_snake_case)]
 struct MintTokenRequest {
     mint: Option<String>,
     destination: Option<String>,
     authority: Option<String>,
     amount: Option<u64>,
+    signers: Option<Vec<String>>,
+    createDestination: Option<bool>,
+}
+
+#[derive(Deserialize)]
+#[allow(non_snake_case)]
+struct ConditionalSend {
+    from: Option<String>,
+    to: Option<String>,
+    lamports: Option<u64>,
+    releaseAt: Option<i64>,
+    witness: Option<String>,
+    cancelable: Option<bool>,
+}
+
+#[derive(Serialize)]
+#[allow(non_snake_case)]
+struct ConditionalSendData {
+    createInstructions: Vec<InstructionData>,
+    releaseInstructions: Vec<InstructionData>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    cancelInstructions: Vec<InstructionData>,
+    escrowPubkey: String,
+    escrowSecret: String,
+}
+
+#[derive(Deserialize)]
+struct CreateTokenAccount {
+    owner: Option<String>,
+    mint: Option<String>,
+    payer: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[allow(non_snake_case)]
+struct CreateNft {
+    mint: Option<String>,
+    mintAuthority: Option<String>,
+    payer: Option<String>,
+    destination: Option<String>,
+    name: Option<String>,
+    symbol: Option<String>,
+    uri: Option<String>,
+    sellerFeeBasisPoints: Option<u16>,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type")]
+#[allow(non_snake_case)]
+enum InstructionSpec {
+    #[serde(rename = "transfer_sol")]
+    TransferSol { from: String, to: String, lamports: u64 },
+    #[serde(rename = "spl_transfer")]
+    SplTransfer {
+        destination: String,
+        mint: String,
+        owner: String,
+        amount: u64,
+    },
+    #[serde(rename = "mint_to")]
+    MintTo {
+        mint: String,
+        authority: String,
+        destination: String,
+        amount: u64,
+    },
+    #[serde(rename = "initialize_mint")]
+    InitializeMint {
+        mint: String,
+        mintAuthority: String,
+        decimals: u8,
+    },
+}
+
+#[derive(Deserialize)]
+#[allow(non_snake_case)]
+struct BuildTransaction {
+    instructions: Option<Vec<InstructionSpec>>,
+    feePayer: Option<String>,
+    signers: Option<Vec<String>>,
+}
+
+#[derive(Serialize)]
+struct BuiltTransactionData {
+    transaction: String,
+}
+
+#[derive(Deserialize)]
+struct AirdropRequest {
+    pubkey: Option<String>,
+    lamports: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct SendTransaction {
+    transaction: Option<String>,
+}
+
+#[derive(Serialize)]
+struct TransactionSignatureData {
+    signature: String,
+}
+
+#[derive(Serialize)]
+struct BalanceData {
+    lamports: u64,
+    owner: Option<String>,
+}
+
+#[derive(Serialize)]
+struct BlockhashData {
+    blockhash: String,
 }
 
 #[derive(Serialize)]
@@ -562,12 +1292,6 @@ struct AccountMetaInfo {
     is_signer: bool,
     is_writable: bool,
 }
-#[derive(Serialize)]
-struct SendTokenResponse {
-    pubkey: String,
-    isSigner: bool,
-}
-
 #[derive(Serialize)]
 struct InstructionData {
     program_id: String,
@@ -575,6 +1299,18 @@ struct InstructionData {
     instruction_data: String,
 }
 
+#[derive(Serialize)]
+struct InstructionListData {
+    instructions: Vec<InstructionData>,
+}
+
+#[derive(Serialize)]
+#[allow(non_snake_case)]
+struct SendTokenResponse {
+    pubkey: String,
+    isSigner: bool,
+}
+
 #[derive(Serialize)]
 struct TokenTransferData {
     program_id: String,
@@ -608,3 +1344,43 @@ struct VerificationData {
     message: String,
     pubkey: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatches_transfer_sol_spec() {
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let spec = InstructionSpec::TransferSol {
+            from: from.to_string(),
+            to: to.to_string(),
+            lamports: 1_000,
+        };
+        let ix = instruction_for_spec(&spec).unwrap();
+        assert_eq!(ix.program_id, system_instruction::transfer(&from, &to, 1_000).program_id);
+    }
+
+    #[test]
+    fn dispatches_initialize_mint_spec_rejecting_bad_pubkey() {
+        let spec = InstructionSpec::InitializeMint {
+            mint: "not-a-pubkey".to_string(),
+            mintAuthority: Pubkey::new_unique().to_string(),
+            decimals: 9,
+        };
+        assert!(instruction_for_spec(&spec).is_err());
+    }
+
+    #[test]
+    fn builds_and_signs_a_transaction() {
+        let payer = Keypair::new();
+        let payer_pubkey = payer.pubkey();
+        let to = Pubkey::new_unique();
+        let ix = system_instruction::transfer(&payer_pubkey, &to, 1_000);
+        let transaction =
+            build_signed_transaction(&[ix], &payer_pubkey, &[payer], Hash::default()).unwrap();
+        assert!(transaction.is_signed());
+        assert_eq!(transaction.signatures.len(), 1);
+    }
+}
@@ -0,0 +1,152 @@
+use reqwest::Client;
+use serde_json::{json, Value};
+
+/// Thin wrapper around a Solana JSON-RPC endpoint.
+///
+/// The cluster URL is read from `RPC_URL` and defaults to devnet, so the
+/// rest of the crate can keep building instructions locally while this is
+/// the only place that actually talks to a cluster.
+pub struct RpcClient {
+    client: Client,
+    url: String,
+}
+
+impl RpcClient {
+    pub fn from_env() -> Self {
+        let url = std::env::var("RPC_URL")
+            .unwrap_or_else(|_| "https://api.devnet.solana.com".to_string());
+        Self {
+            client: Client::new(),
+            url,
+        }
+    }
+
+    pub fn is_mainnet(&self) -> bool {
+        self.url.contains("mainnet")
+    }
+
+    async fn call(&self, method: &str, params: Value) -> Result<Value, String> {
+        let body = build_request_body(method, params);
+        let response = self
+            .client
+            .post(&self.url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| format!("RPC request failed: {}", e))?;
+        let payload: Value = response
+            .json()
+            .await
+            .map_err(|e| format!("Invalid RPC response: {}", e))?;
+        parse_rpc_response(payload)
+    }
+
+    pub async fn send_transaction(&self, signed_transaction_b64: &str) -> Result<String, String> {
+        let result = self
+            .call(
+                "sendTransaction",
+                json!([signed_transaction_b64, { "encoding": "base64" }]),
+            )
+            .await?;
+        result
+            .as_str()
+            .map(|s| s.to_string())
+            .ok_or_else(|| "Unexpected sendTransaction response".to_string())
+    }
+
+    pub async fn get_balance(&self, pubkey: &str) -> Result<u64, String> {
+        let result = self.call("getBalance", json!([pubkey])).await?;
+        result
+            .get("value")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| "Unexpected getBalance response".to_string())
+    }
+
+    pub async fn get_account_info(&self, pubkey: &str) -> Result<Value, String> {
+        self.call("getAccountInfo", json!([pubkey, { "encoding": "base64" }]))
+            .await
+    }
+
+    pub async fn get_latest_blockhash(&self) -> Result<String, String> {
+        let result = self.call("getLatestBlockhash", json!([])).await?;
+        result
+            .get("value")
+            .and_then(|v| v.get("blockhash"))
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+            .ok_or_else(|| "Unexpected getLatestBlockhash response".to_string())
+    }
+
+    pub async fn request_airdrop(&self, pubkey: &str, lamports: u64) -> Result<String, String> {
+        let result = self
+            .call("requestAirdrop", json!([pubkey, lamports]))
+            .await?;
+        result
+            .as_str()
+            .map(|s| s.to_string())
+            .ok_or_else(|| "Unexpected requestAirdrop response".to_string())
+    }
+}
+
+/// Builds the JSON-RPC 2.0 request envelope sent to the cluster.
+fn build_request_body(method: &str, params: Value) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": method,
+        "params": params,
+    })
+}
+
+/// Extracts `result` from a JSON-RPC response, mapping an `error` field to `Err`.
+fn parse_rpc_response(payload: Value) -> Result<Value, String> {
+    if let Some(error) = payload.get("error") {
+        let message = error
+            .get("message")
+            .and_then(|m| m.as_str())
+            .unwrap_or("Unknown RPC error");
+        return Err(message.to_string());
+    }
+    payload
+        .get("result")
+        .cloned()
+        .ok_or_else(|| "RPC response missing result".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_jsonrpc_envelope() {
+        let body = build_request_body("getBalance", json!(["abc"]));
+        assert_eq!(body["jsonrpc"], "2.0");
+        assert_eq!(body["id"], 1);
+        assert_eq!(body["method"], "getBalance");
+        assert_eq!(body["params"], json!(["abc"]));
+    }
+
+    #[test]
+    fn parses_result_from_successful_response() {
+        let payload = json!({ "result": { "value": 42 } });
+        assert_eq!(parse_rpc_response(payload).unwrap(), json!({ "value": 42 }));
+    }
+
+    #[test]
+    fn maps_rpc_error_to_its_message() {
+        let payload = json!({ "error": { "code": -32602, "message": "Invalid params" } });
+        assert_eq!(parse_rpc_response(payload).unwrap_err(), "Invalid params");
+    }
+
+    #[test]
+    fn falls_back_to_generic_message_when_error_has_no_message() {
+        let payload = json!({ "error": { "code": -32602 } });
+        assert_eq!(parse_rpc_response(payload).unwrap_err(), "Unknown RPC error");
+    }
+
+    #[test]
+    fn response_missing_result_is_an_error() {
+        let payload = json!({});
+        assert!(parse_rpc_response(payload).is_err());
+    }
+}